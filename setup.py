@@ -81,6 +81,7 @@ setup(
         "search_conversations",
         "realtime_search",
         "search_cli",
+        "terminal_style",
     ],
     entry_points={
         "console_scripts": [
@@ -9,10 +9,118 @@ readable markdown files.
 
 import argparse
 import json
+import os
+import re
+import time
 from datetime import datetime
+from functools import lru_cache
 from pathlib import Path
 from typing import Dict, List, Optional, Tuple
 
+# Handle both package and direct execution imports
+try:
+    from .terminal_style import ASCII_ENV_VAR, ICONS, NO_COLOR_ENV_VAR, highlight, icon
+except ImportError:
+    from terminal_style import ASCII_ENV_VAR, ICONS, NO_COLOR_ENV_VAR, highlight, icon
+
+
+@lru_cache(maxsize=None)
+def decode_project_name(encoded: str) -> str:
+    """Reconstruct a project path from Claude's encoded directory name.
+
+    Claude Code names each project directory after its path with every
+    non-alphanumeric character replaced by a hyphen, so ``/Users/me/my-proj``
+    is stored as ``-Users-me-my-proj``. Since that is ambiguous, the path is
+    rebuilt by matching against existing directories, backtracking until the
+    whole name resolves. Whatever cannot be matched falls back to treating
+    every hyphen as a separator. Paths under the home directory are shown
+    with a leading ``~``.
+    """
+    drive = re.match(r"^([A-Za-z])--", encoded)
+    if encoded.startswith("-"):
+        path = Path("/")
+        rest = encoded[1:]
+    elif drive:
+        path = Path(f"{drive.group(1)}:/")
+        rest = encoded[3:]
+    else:
+        return encoded
+
+    path, rest = _resolve_project_path(path, rest)
+    if rest:
+        path = path.joinpath(*[part for part in rest.split("-") if part])
+
+    try:
+        relative = path.relative_to(Path.home())
+    except ValueError:
+        return path.as_posix()
+    return "~" if relative == Path(".") else f"~/{relative.as_posix()}"
+
+
+def _resolve_project_path(path: Path, rest: str) -> Tuple[Path, str]:
+    """Match the encoded remainder ``rest`` against directories below ``path``.
+
+    Candidates are tried longest first, backtracking when a choice leads to a
+    dead end. Returns the deepest path reached and the part still unmatched,
+    which is empty when the whole name resolved.
+    """
+    if not rest:
+        return path, rest
+
+    # Match on the name first so only plausible entries need a stat call
+    try:
+        candidates = [
+            child
+            for child in path.iterdir()
+            if rest == _encode_path_part(child.name)
+            or rest.startswith(_encode_path_part(child.name) + "-")
+        ]
+    except OSError:
+        candidates = []
+
+    best = (path, rest)
+    for child in sorted(candidates, key=lambda child: len(child.name), reverse=True):
+        if not child.is_dir():
+            continue
+        name = child.name
+        resolved, remaining = _resolve_project_path(path / name, rest[len(name) + 1:])
+        if not remaining:
+            return resolved, remaining
+        if len(remaining) < len(best[1]):
+            best = (resolved, remaining)
+    return best
+
+
+def _encode_path_part(name: str) -> str:
+    """Encode a path component the way Claude Code does for project directories."""
+    return re.sub(r"[^A-Za-z0-9]", "-", name)
+
+
+def truncate_left(text: str, width: int) -> str:
+    """Shorten text to ``width`` characters by dropping the start, marked with ``…``.
+
+    Decoded project paths share long prefixes, so the end is what tells them apart.
+    """
+    if len(text) <= width:
+        return text
+    if width <= 1:
+        return "…"[:max(width, 0)]
+    return "…" + text[-(width - 1):]
+
+
+# Emoji that extract_conversation puts in detailed message content; the viewer
+# swaps them for the current icon so --ascii applies without changing exports
+_CONTENT_ICONS = ("tool", "export", "info")
+
+
+def _display_icons(line: str) -> str:
+    """Replace a leading content emoji with the icon for the current mode."""
+    for name in _CONTENT_ICONS:
+        emoji = ICONS[name][0]
+        if line.startswith(emoji):
+            return icon(name) + line[len(emoji):]
+    return line
+
 
 class ClaudeConversationExtractor:
     """Extract and convert Claude Code conversations from JSONL to markdown."""
@@ -20,6 +128,7 @@ class ClaudeConversationExtractor:
     def __init__(self, output_dir: Optional[Path] = None):
         """Initialize the extractor with Claude's directory and output location."""
         self.claude_dir = Path.home() / ".claude" / "projects"
+        self.last_run: Optional[Dict] = None
 
         if output_dir:
             self.output_dir = Path(output_dir)
@@ -50,7 +159,7 @@ class ClaudeConversationExtractor:
                 self.output_dir = Path.cwd() / "claude-logs"
                 self.output_dir.mkdir(exist_ok=True)
 
-        print(f"📁 Saving logs to: {self.output_dir}")
+        print(f"{icon('folder')} Saving logs to: {self.output_dir}")
 
     def find_sessions(self, project_path: Optional[str] = None) -> List[Path]:
         """Find all JSONL session files, sorted by most recent first."""
@@ -158,7 +267,7 @@ class ClaudeConversationExtractor:
                         continue
 
         except Exception as e:
-            print(f"❌ Error reading file {jsonl_path}: {e}")
+            print(f"{icon('error')} Error reading file {jsonl_path}: {e}")
 
         return conversation
 
@@ -188,20 +297,42 @@ class ClaudeConversationExtractor:
         else:
             return str(content)
 
-    def display_conversation(self, jsonl_path: Path, detailed: bool = False) -> None:
+    def display_conversation(
+        self,
+        jsonl_path: Path,
+        detailed: bool = False,
+        query: Optional[str] = None,
+        regex: bool = False,
+        case_sensitive: bool = False,
+    ) -> None:
         """Display a conversation in the terminal with pagination.
         
         Args:
             jsonl_path: Path to the JSONL file
             detailed: If True, include tool use and system messages
+            query: Search query to jump to and highlight; plain queries match any
+                of their words
+            regex: If True, treat query as a regular expression
+            case_sensitive: If True, match query case-sensitively
         """
         try:
             # Extract conversation
             messages = self.extract_conversation(jsonl_path, detailed=detailed)
             
             if not messages:
-                print("❌ No messages found in conversation")
+                print(f"{icon('error')} No messages found in conversation")
                 return
+
+            pattern = self._compile_highlight(query, regex, case_sensitive)
+            # Zero-width matches (e.g. "x*") match everywhere, so they don't count
+            match_indices = []
+            if pattern:
+                match_indices = [
+                    i
+                    for i, msg in enumerate(messages)
+                    if any(m.group(0) for m in pattern.finditer(msg["content"]))
+                ]
+            start = match_indices[0] if match_indices else 0
             
             # Get session info
             session_id = jsonl_path.stem
@@ -209,7 +340,7 @@ class ClaudeConversationExtractor:
             # Clear screen and show header
             print("\033[2J\033[H", end="")  # Clear screen
             print("=" * 60)
-            print(f"📄 Viewing: {jsonl_path.parent.name}")
+            print(f"{icon('file')} Viewing: {decode_project_name(jsonl_path.parent.name)}")
             print(f"Session: {session_id[:8]}...")
             
             # Get timestamp from first message
@@ -221,32 +352,40 @@ class ClaudeConversationExtractor:
                 except Exception:
                     pass
             
+            if start:
+                print(f"Jumped to first match (message {start + 1} of {len(messages)})")
             print("=" * 60)
             print("↑↓ to scroll • Q to quit • Enter to continue\n")
             
             # Display messages with pagination
             lines_shown = 8  # Header lines
             lines_per_page = 30
+            prompt = "\n[Enter] Continue • [Q] Quit: "
+            if match_indices:
+                prompt = "\n[Enter] Continue • [N]/[P] Next/Previous match • [Q] Quit: "
             
-            for i, msg in enumerate(messages):
+            i = start
+            while i < len(messages):
+                msg = messages[i]
+                jump_to = None
                 role = msg["role"]
                 content = msg["content"]
                 
                 # Format role display
                 if role == "user" or role == "human":
                     print(f"\n{'─' * 40}")
-                    print(f"👤 HUMAN:")
+                    print(f"{icon('user')} HUMAN:")
                     print(f"{'─' * 40}")
                 elif role == "assistant":
                     print(f"\n{'─' * 40}")
-                    print(f"🤖 CLAUDE:")
+                    print(f"{icon('assistant')} CLAUDE:")
                     print(f"{'─' * 40}")
                 elif role == "tool_use":
-                    print(f"\n🔧 TOOL USE:")
+                    print(f"\n{icon('tool')} TOOL USE:")
                 elif role == "tool_result":
-                    print(f"\n📤 TOOL RESULT:")
+                    print(f"\n{icon('export')} TOOL RESULT:")
                 elif role == "system":
-                    print(f"\nℹ️ SYSTEM:")
+                    print(f"\n{icon('info')} SYSTEM:")
                 else:
                     print(f"\n{role.upper()}:")
                 
@@ -255,35 +394,75 @@ class ClaudeConversationExtractor:
                 max_lines_per_msg = 50
                 
                 for line_idx, line in enumerate(lines[:max_lines_per_msg]):
+                    line = _display_icons(line)
                     # Wrap very long lines
                     if len(line) > 100:
                         line = line[:97] + "..."
+                    if pattern:
+                        line = pattern.sub(
+                            lambda m: highlight(m.group(0)) if m.group(0) else "", line
+                        )
                     print(line)
                     lines_shown += 1
                     
                     # Check if we need to paginate
                     if lines_shown >= lines_per_page:
-                        response = input("\n[Enter] Continue • [Q] Quit: ").strip().upper()
+                        response = input(prompt).strip().upper()
                         if response == "Q":
-                            print("\n👋 Stopped viewing")
+                            print(f"\n{icon('bye')} Stopped viewing")
                             return
                         # Clear screen for next page
                         print("\033[2J\033[H", end="")
                         lines_shown = 0
+                        if match_indices and response in ("N", "P"):
+                            # Cycle through matching messages, wrapping at either end
+                            if response == "N":
+                                later = [j for j in match_indices if j > i]
+                                jump_to = later[0] if later else match_indices[0]
+                            else:
+                                earlier = [j for j in match_indices if j < i]
+                                jump_to = earlier[-1] if earlier else match_indices[-1]
+                            position = match_indices.index(jump_to) + 1
+                            print(
+                                f"Match {position} of {len(match_indices)} "
+                                f"(message {jump_to + 1} of {len(messages)})"
+                            )
+                            lines_shown = 1
+                            break
+                
+                if jump_to is not None:
+                    i = jump_to
+                    continue
                 
                 if len(lines) > max_lines_per_msg:
                     print(f"... [{len(lines) - max_lines_per_msg} more lines truncated]")
                     lines_shown += 1
+                i += 1
             
             print("\n" + "=" * 60)
-            print("📄 End of conversation")
+            print(f"{icon('file')} End of conversation")
             print("=" * 60)
             input("\nPress Enter to continue...")
             
         except Exception as e:
-            print(f"❌ Error displaying conversation: {e}")
+            print(f"{icon('error')} Error displaying conversation: {e}")
             input("\nPress Enter to continue...")
 
+    def _compile_highlight(
+        self, query: Optional[str], regex: bool, case_sensitive: bool
+    ) -> Optional["re.Pattern"]:
+        """Build the pattern used to find and highlight a search query."""
+        if not query or not query.strip():
+            return None
+        flags = 0 if case_sensitive else re.IGNORECASE
+        if regex:
+            try:
+                return re.compile(query, flags)
+            except re.error:
+                return None
+        words = sorted(query.split(), key=len, reverse=True)
+        return re.compile("|".join(re.escape(word) for word in words), flags)
+
     def save_as_markdown(
         self, conversation: List[Dict[str, str]], session_id: str
     ) -> Optional[Path]:
@@ -539,7 +718,7 @@ class ClaudeConversationExtractor:
         elif format == "html":
             return self.save_as_html(conversation, session_id)
         else:
-            print(f"❌ Unsupported format: {format}")
+            print(f"{icon('error')} Unsupported format: {format}")
             return None
 
     def get_conversation_preview(self, session_path: Path) -> Tuple[str, int]:
@@ -629,21 +808,18 @@ class ClaudeConversationExtractor:
         sessions = self.find_sessions()
 
         if not sessions:
-            print("❌ No Claude sessions found in ~/.claude/projects/")
-            print("💡 Make sure you've used Claude Code and have conversations saved.")
+            print(f"{icon('error')} No Claude sessions found in ~/.claude/projects/")
+            print(f"{icon('tip')} Make sure you've used Claude Code and have conversations saved.")
             return []
 
-        print(f"\n📚 Found {len(sessions)} Claude sessions:\n")
+        print(f"\n{icon('library')} Found {len(sessions)} Claude sessions:\n")
         print("=" * 80)
 
         # Show all sessions if no limit specified
         sessions_to_show = sessions[:limit] if limit else sessions
         for i, session in enumerate(sessions_to_show, 1):
-            # Clean up project name (remove hyphens, make readable)
-            project = session.parent.name.replace('-', ' ').strip()
-            if project.startswith("Users"):
-                project = "~/" + "/".join(project.split()[2:]) if len(project.split()) > 2 else "Home"
-            
+            project = decode_project_name(session.parent.name)
+
             session_id = session.stem
             modified = datetime.fromtimestamp(session.stat().st_mtime)
 
@@ -655,12 +831,12 @@ class ClaudeConversationExtractor:
             preview, msg_count = self.get_conversation_preview(session)
 
             # Print formatted info
-            print(f"\n{i}. 📁 {project}")
-            print(f"   📄 Session: {session_id[:8]}...")
-            print(f"   📅 Modified: {modified.strftime('%Y-%m-%d %H:%M')}")
-            print(f"   💬 Messages: {msg_count}")
-            print(f"   💾 Size: {size_kb:.1f} KB")
-            print(f"   📝 Preview: \"{preview}...\"")
+            print(f"\n{i}. {icon('folder')} {project}")
+            print(f"   {icon('file')} Session: {session_id[:8]}...")
+            print(f"   {icon('calendar')} Modified: {modified.strftime('%Y-%m-%d %H:%M')}")
+            print(f"   {icon('messages')} Messages: {msg_count}")
+            print(f"   {icon('disk')} Size: {size_kb:.1f} KB")
+            print(f"   {icon('note')} Preview: \"{preview}...\"")
 
         print("\n" + "=" * 80)
         return sessions[:limit]
@@ -679,26 +855,91 @@ class ClaudeConversationExtractor:
         """
         success = 0
         total = len(indices)
+        start = time.monotonic()
+        self.last_run = {
+            "format": format,
+            "processed": 0,
+            "bytes_written": 0,
+            "skipped": [],
+            "failed": [],
+            "elapsed": 0.0,
+        }
 
         for idx in indices:
+            self.last_run["processed"] += 1
             if 0 <= idx < len(sessions):
                 session_path = sessions[idx]
                 conversation = self.extract_conversation(session_path, detailed=detailed)
                 if conversation:
-                    output_path = self.save_conversation(conversation, session_path.stem, format=format)
+                    try:
+                        output_path = self.save_conversation(
+                            conversation, session_path.stem, format=format
+                        )
+                    except OSError as e:
+                        output_path = None
+                        reason = str(e)
+                    else:
+                        reason = "could not be saved"
+                    if output_path is None:
+                        self.last_run["failed"].append((session_path.name, reason))
+                        print(f"{icon('error')} Failed session {idx + 1}: {reason}")
+                        continue
                     success += 1
+                    try:
+                        self.last_run["bytes_written"] += output_path.stat().st_size
+                    except OSError:
+                        pass
                     msg_count = len(conversation)
                     print(
-                        f"✅ {success}/{total}: {output_path.name} "
+                        f"{icon('success')} {success}/{total}: {output_path.name} "
                         f"({msg_count} messages)"
                     )
                 else:
-                    print(f"⏭️  Skipped session {idx + 1} (no conversation)")
+                    self.last_run["skipped"].append((session_path.name, "no conversation"))
+                    print(f"{icon('skip')}  Skipped session {idx + 1} (no conversation)")
             else:
-                print(f"❌ Invalid session number: {idx + 1}")
+                self.last_run["failed"].append((f"#{idx + 1}", "invalid session number"))
+                print(f"{icon('error')} Invalid session number: {idx + 1}")
 
+        self.last_run["elapsed"] = time.monotonic() - start
         return success, total
 
+    def write_run_summary(self) -> Optional[Path]:
+        """Print a summary of the last extract_multiple run and log it to a file.
+
+        The summary is appended to ``export-summary.log`` in the output
+        directory so headless runs leave a record next to the exported files.
+        """
+        run = self.last_run
+        if run is None:
+            return None
+
+        lines = [
+            f"Export run {datetime.now().strftime('%Y-%m-%d %H:%M:%S')} "
+            f"({run['format']})",
+            f"  Processed:     {run['processed']}",
+            f"  Bytes written: {run['bytes_written']}",
+            f"  Skipped:       {len(run['skipped'])}",
+        ]
+        for name, reason in run["skipped"]:
+            lines.append(f"    - {name}: {reason}")
+        lines.append(f"  Failed:        {len(run['failed'])}")
+        for name, reason in run["failed"]:
+            lines.append(f"    - {name}: {reason}")
+        lines.append(f"  Elapsed:       {run['elapsed']:.2f}s")
+
+        print(f"\n{icon('stats')} Summary")
+        print("\n".join(lines[1:]))
+
+        log_path = self.output_dir / "export-summary.log"
+        try:
+            with open(log_path, "a", encoding="utf-8") as f:
+                f.write("\n".join(lines) + "\n\n")
+        except OSError as e:
+            print(f"{icon('warning')}  Could not write summary log: {e}")
+            return None
+        return log_path
+
 
 def main():
     parser = argparse.ArgumentParser(
@@ -717,6 +958,7 @@ Examples:
   %(prog)s --format json --all       # Export all as JSON
   %(prog)s --format html --extract 1 # Export session 1 as HTML
   %(prog)s --detailed --extract 1    # Include tool use & system messages
+  %(prog)s --ascii --list            # Plain ASCII icons instead of emoji
         """,
     )
     parser.add_argument("--list", action="store_true", help="List recent sessions")
@@ -786,9 +1028,26 @@ Examples:
         action="store_true",
         help="Include tool use, MCP responses, and system messages in export"
     )
+    parser.add_argument(
+        "--ascii",
+        action="store_true",
+        help=f"Use ASCII icons instead of emoji (same as {ASCII_ENV_VAR}=1)",
+    )
+
+    parser.add_argument(
+        "--no-color",
+        action="store_true",
+        help=f"Disable colored output (same as {NO_COLOR_ENV_VAR}=1)",
+    )
 
     args = parser.parse_args()
 
+    # Set the environment so the interactive UI and search pick these up too
+    if args.ascii:
+        os.environ[ASCII_ENV_VAR] = "1"
+    if args.no_color:
+        os.environ[NO_COLOR_ENV_VAR] = "1"
+
     # Handle interactive mode
     if args.interactive or (args.export and args.export.lower() == "logs"):
         from interactive_ui import main as interactive_main
@@ -822,21 +1081,21 @@ Examples:
             try:
                 date_from = datetime.strptime(args.search_date_from, "%Y-%m-%d")
             except ValueError:
-                print(f"❌ Invalid date format: {args.search_date_from}")
+                print(f"{icon('error')} Invalid date format: {args.search_date_from}")
                 return
 
         if args.search_date_to:
             try:
                 date_to = datetime.strptime(args.search_date_to, "%Y-%m-%d")
             except ValueError:
-                print(f"❌ Invalid date format: {args.search_date_to}")
+                print(f"{icon('error')} Invalid date format: {args.search_date_to}")
                 return
 
         # Speaker filter
         speaker_filter = None if args.search_speaker == "both" else args.search_speaker
 
         # Perform search
-        print(f"🔍 Searching for: {query}")
+        print(f"{icon('search')} Searching for: {query}")
         results = searcher.search(
             query=query,
             mode=mode,
@@ -848,10 +1107,10 @@ Examples:
         )
 
         if not results:
-            print("❌ No matches found.")
+            print(f"{icon('error')} No matches found.")
             return
 
-        print(f"\n✅ Found {len(results)} matches across conversations:")
+        print(f"\n{icon('success')} Found {len(results)} matches across conversations:")
 
         # Group and display results
         results_by_file = {}
@@ -864,7 +1123,10 @@ Examples:
         file_paths_list = []
         for file_path, file_results in results_by_file.items():
             file_paths_list.append(file_path)
-            print(f"\n{len(file_paths_list)}. 📄 {file_path.parent.name} ({len(file_results)} matches)")
+            project = decode_project_name(file_path.parent.name)
+            print(
+                f"\n{len(file_paths_list)}. {icon('file')} {project} ({len(file_results)} matches)"
+            )
             # Show first match preview
             first = file_results[0]
             print(f"   {first.speaker}: {first.matched_content[:100]}...")
@@ -880,10 +1142,18 @@ Examples:
                     view_num = int(view_choice)
                     if 1 <= view_num <= len(file_paths_list):
                         selected_path = file_paths_list[view_num - 1]
-                        extractor.display_conversation(selected_path, detailed=args.detailed)
+                        extractor.display_conversation(
+                            selected_path,
+                            detailed=args.detailed,
+                            query=query,
+                            regex=mode == "regex",
+                            case_sensitive=args.case_sensitive,
+                        )
                         
                         # Offer to extract after viewing
-                        extract_choice = input("\n📤 Extract this conversation? (y/N): ").strip().lower()
+                        extract_choice = input(
+                            f"\n{icon('export')} Extract this conversation? (y/N): "
+                        ).strip().lower()
                         if extract_choice == 'y':
                             conversation = extractor.extract_conversation(selected_path, detailed=args.detailed)
                             if conversation:
@@ -894,9 +1164,9 @@ Examples:
                                     output = extractor.save_as_html(conversation, session_id)
                                 else:
                                     output = extractor.save_as_markdown(conversation, session_id)
-                                print(f"✅ Saved: {output.name}")
+                                print(f"{icon('success')} Saved: {output.name}")
             except (EOFError, KeyboardInterrupt):
-                print("\n👋 Cancelled")
+                print(f"\n{icon('bye')} Cancelled")
         
         return
 
@@ -926,42 +1196,54 @@ Examples:
                 idx = int(num.strip()) - 1  # Convert to 0-based index
                 indices.append(idx)
             except ValueError:
-                print(f"❌ Invalid session number: {num}")
+                print(f"{icon('error')} Invalid session number: {num}")
                 continue
 
         if indices:
-            print(f"\n📤 Extracting {len(indices)} session(s) as {args.format.upper()}...")
+            print(
+                f"\n{icon('export')} Extracting {len(indices)} session(s) "
+                f"as {args.format.upper()}..."
+            )
             if args.detailed:
-                print("📋 Including detailed tool use and system messages")
+                print(f"{icon('details')} Including detailed tool use and system messages")
             success, total = extractor.extract_multiple(
                 sessions, indices, format=args.format, detailed=args.detailed
             )
-            print(f"\n✅ Successfully extracted {success}/{total} sessions")
+            print(f"\n{icon('success')} Successfully extracted {success}/{total} sessions")
+            extractor.write_run_summary()
 
     elif args.recent:
         sessions = extractor.find_sessions()
         limit = min(args.recent, len(sessions))
-        print(f"\n📤 Extracting {limit} most recent sessions as {args.format.upper()}...")
+        print(
+            f"\n{icon('export')} Extracting {limit} most recent sessions "
+            f"as {args.format.upper()}..."
+        )
         if args.detailed:
-            print("📋 Including detailed tool use and system messages")
+            print(f"{icon('details')} Including detailed tool use and system messages")
 
         indices = list(range(limit))
         success, total = extractor.extract_multiple(
             sessions, indices, format=args.format, detailed=args.detailed
         )
-        print(f"\n✅ Successfully extracted {success}/{total} sessions")
+        print(f"\n{icon('success')} Successfully extracted {success}/{total} sessions")
+        extractor.write_run_summary()
 
     elif args.all:
         sessions = extractor.find_sessions()
-        print(f"\n📤 Extracting all {len(sessions)} sessions as {args.format.upper()}...")
+        print(
+            f"\n{icon('export')} Extracting all {len(sessions)} sessions "
+            f"as {args.format.upper()}..."
+        )
         if args.detailed:
-            print("📋 Including detailed tool use and system messages")
+            print(f"{icon('details')} Including detailed tool use and system messages")
 
         indices = list(range(len(sessions)))
         success, total = extractor.extract_multiple(
             sessions, indices, format=args.format, detailed=args.detailed
         )
-        print(f"\n✅ Successfully extracted {success}/{total} sessions")
+        print(f"\n{icon('success')} Successfully extracted {success}/{total} sessions")
+        extractor.write_run_summary()
 
 
 def launch_interactive():
@@ -996,19 +1278,21 @@ def launch_interactive():
         
         if selected_file:
             # View the selected conversation
-            extractor.display_conversation(selected_file)
+            extractor.display_conversation(selected_file, query=rts.state.query)
             
             # Offer to extract
             try:
-                extract_choice = input("\n📤 Extract this conversation? (y/N): ").strip().lower()
+                extract_choice = input(
+                    f"\n{icon('export')} Extract this conversation? (y/N): "
+                ).strip().lower()
                 if extract_choice == 'y':
                     conversation = extractor.extract_conversation(selected_file)
                     if conversation:
                         session_id = selected_file.stem
                         output = extractor.save_as_markdown(conversation, session_id)
-                        print(f"✅ Saved: {output.name}")
+                        print(f"{icon('success')} Saved: {output.name}")
             except (EOFError, KeyboardInterrupt):
-                print("\n👋 Cancelled")
+                print(f"\n{icon('bye')} Cancelled")
     else:
         # If other arguments are provided, run the normal CLI
         main()
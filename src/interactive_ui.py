@@ -11,14 +11,24 @@ from typing import List, Optional
 
 # Handle both package and direct execution imports
 try:
-    from .extract_claude_logs import ClaudeConversationExtractor
+    from .extract_claude_logs import (
+        ClaudeConversationExtractor,
+        decode_project_name,
+        truncate_left,
+    )
     from .realtime_search import RealTimeSearch, create_smart_searcher
     from .search_conversations import ConversationSearcher
+    from .terminal_style import ansi, icon
 except ImportError:
     # Fallback for direct execution or when not installed as package
-    from extract_claude_logs import ClaudeConversationExtractor
+    from extract_claude_logs import (
+        ClaudeConversationExtractor,
+        decode_project_name,
+        truncate_left,
+    )
     from realtime_search import RealTimeSearch, create_smart_searcher
     from search_conversations import ConversationSearcher
+    from terminal_style import ansi, icon
 
 
 class InteractiveUI:
@@ -39,9 +49,9 @@ class InteractiveUI:
     def print_banner(self):
         """Print a cool ASCII banner"""
         # Bright magenta color
-        MAGENTA = "\033[95m"
-        RESET = "\033[0m"
-        BOLD = "\033[1m"
+        MAGENTA = ansi("95")
+        RESET = ansi("0")
+        BOLD = ansi("1")
 
         banner = f"""{MAGENTA}{BOLD}
 
@@ -70,7 +80,7 @@ class InteractiveUI:
         """Simple folder selection dialog"""
         self.clear_screen()
         self.print_banner()
-        print("\n📁 Where would you like to save your conversations?\n")
+        print(f"\n{icon('folder')} Where would you like to save your conversations?\n")
 
         # Suggest common locations
         home = Path.home()
@@ -100,7 +110,7 @@ class InteractiveUI:
             elif choice.isdigit() and 1 <= int(choice) <= len(suggestions):
                 return suggestions[int(choice) - 1]
             else:
-                print("❌ Invalid choice. Please try again.")
+                print(f"{icon('error')} Invalid choice. Please try again.")
 
     def show_sessions_menu(self) -> List[int]:
         """Display sessions and let user select which to extract"""
@@ -108,25 +118,25 @@ class InteractiveUI:
         self.print_banner()
 
         # Get all sessions
-        print("\n🔍 Finding your Claude conversations...")
+        print(f"\n{icon('search')} Finding your Claude conversations...")
         self.sessions = self.extractor.find_sessions()
 
         if not self.sessions:
-            print("\n❌ No Claude conversations found!")
+            print(f"\n{icon('error')} No Claude conversations found!")
             print("Make sure you've used Claude Code at least once.")
             input("\nPress Enter to exit...")
             return []
 
-        print(f"\n✅ Found {len(self.sessions)} conversations!\n")
+        print(f"\n{icon('success')} Found {len(self.sessions)} conversations!\n")
 
         # Display sessions
         for i, session_path in enumerate(self.sessions[:20], 1):  # Show max 20
-            project = session_path.parent.name
+            project = decode_project_name(session_path.parent.name)
             modified = datetime.fromtimestamp(session_path.stat().st_mtime)
             size_kb = session_path.stat().st_size / 1024
 
             date_str = modified.strftime("%Y-%m-%d %H:%M")
-            print(f"  {i:2d}. [{date_str}] {project[:30]:<30} ({size_kb:.1f} KB)")
+            print(f"  {i:2d}. [{date_str}] {truncate_left(project, 30):<30} ({size_kb:.1f} KB)")
 
         if len(self.sessions) > 20:
             print(f"\n  ... and {len(self.sessions) - 20} more conversations")
@@ -156,16 +166,16 @@ class InteractiveUI:
                     if all(0 <= i < len(self.sessions) for i in indices):
                         return indices
                     else:
-                        print("❌ Invalid selection. Please use valid numbers.")
+                        print(f"{icon('error')} Invalid selection. Please use valid numbers.")
                 except ValueError:
-                    print("❌ Invalid format. Use comma-separated numbers.")
+                    print(f"{icon('error')} Invalid format. Use comma-separated numbers.")
             elif choice == "F":
                 # Search functionality
                 search_results = self.search_conversations()
                 if search_results:
                     return search_results
             else:
-                print("❌ Invalid choice. Please try again.")
+                print(f"{icon('error')} Invalid choice. Please try again.")
 
     def show_progress(self, current: int, total: int, message: str = ""):
         """Display a simple progress bar"""
@@ -187,16 +197,18 @@ class InteractiveUI:
 
         if selected_file:
             # View the selected conversation
-            self.extractor.display_conversation(Path(selected_file))
+            self.extractor.display_conversation(Path(selected_file), query=rts.state.query)
             
             # Ask if user wants to extract it
-            extract_choice = input("\n📤 Extract this conversation? (y/N): ").strip().lower()
+            extract_choice = input(
+                f"\n{icon('export')} Extract this conversation? (y/N): "
+            ).strip().lower()
             if extract_choice == 'y':
                 try:
                     index = self.sessions.index(Path(selected_file))
                     return [index]
                 except ValueError:
-                    print("\n❌ Error: Selected file not found in sessions list")
+                    print(f"\n{icon('error')} Error: Selected file not found in sessions list")
                     input("\nPress Enter to continue...")
             
             # Return empty to go back to menu
@@ -206,7 +218,7 @@ class InteractiveUI:
 
     def extract_conversations(self, indices: List[int], output_dir: Path) -> int:
         """Extract selected conversations with progress display"""
-        print(f"\n📤 Extracting {len(indices)} conversations...\n")
+        print(f"\n{icon('export')} Extracting {len(indices)} conversations...\n")
 
         # Update the extractor's output directory
         self.extractor.output_dir = output_dir
@@ -217,7 +229,8 @@ class InteractiveUI:
         )
 
         print(
-            f"\n\n✅ Successfully extracted {success_count}/{total_count} conversations!"
+            f"\n\n{icon('success')} Successfully extracted "
+            f"{success_count}/{total_count} conversations!"
         )
         return success_count
 
@@ -239,13 +252,13 @@ class InteractiveUI:
             # Get output folder
             output_dir = self.get_folder_selection()
             if not output_dir:
-                print("\n👋 Goodbye!")
+                print(f"\n{icon('bye')} Goodbye!")
                 return
 
             # Get session selection
             selected_indices = self.show_sessions_menu()
             if not selected_indices:
-                print("\n👋 Goodbye!")
+                print(f"\n{icon('bye')} Goodbye!")
                 return
 
             # Create output directory if needed
@@ -255,22 +268,24 @@ class InteractiveUI:
             success_count = self.extract_conversations(selected_indices, output_dir)
 
             if success_count > 0:
-                print(f"\n📁 Files saved to: {output_dir}")
+                print(f"\n{icon('folder')} Files saved to: {output_dir}")
 
                 # Offer to open the folder
-                open_choice = input("\n🗂️  Open output folder? (Y/n): ").strip().lower()
+                open_choice = input(
+                    f"\n{icon('open_folder')}  Open output folder? (Y/n): "
+                ).strip().lower()
                 if open_choice != "n":
                     self.open_folder(output_dir)
 
             else:
-                print("\n❌ No conversations were extracted.")
+                print(f"\n{icon('error')} No conversations were extracted.")
 
-            input("\n✨ Press Enter to exit...")
+            input(f"\n{icon('sparkle')} Press Enter to exit...")
 
         except KeyboardInterrupt:
-            print("\n\n👋 Goodbye!")
+            print(f"\n\n{icon('bye')} Goodbye!")
         except Exception as e:
-            print(f"\n❌ Error: {e}")
+            print(f"\n{icon('error')} Error: {e}")
             input("\nPress Enter to exit...")
 
 
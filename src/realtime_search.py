@@ -5,6 +5,7 @@ Properly handles arrow keys without printing escape sequences.
 """
 
 import os
+import signal
 import sys
 import threading
 import time
@@ -13,6 +14,14 @@ from datetime import datetime
 from pathlib import Path
 from typing import List, Optional
 
+# Handle both package and direct execution imports
+try:
+    from .extract_claude_logs import decode_project_name, truncate_left
+    from .terminal_style import highlight, icon
+except ImportError:
+    from extract_claude_logs import decode_project_name, truncate_left
+    from terminal_style import highlight, icon
+
 # Platform-specific imports for keyboard handling
 if sys.platform == "win32":
     import msvcrt
@@ -43,6 +52,7 @@ class KeyboardHandler:
 
     def __init__(self):
         self.old_settings = None
+        self.old_handlers = {}
         if sys.platform != "win32":
             self.stdin_fd = sys.stdin.fileno()
 
@@ -51,12 +61,30 @@ class KeyboardHandler:
         if sys.platform != "win32":
             self.old_settings = termios.tcgetattr(self.stdin_fd)
             tty.setraw(self.stdin_fd)
+            # Default SIGTERM/SIGHUP handling skips __exit__ and leaves the
+            # terminal in raw mode, so turn them into a normal exit
+            if threading.current_thread() is threading.main_thread():
+                for sig in (signal.SIGTERM, signal.SIGHUP):
+                    self.old_handlers[sig] = signal.signal(sig, self._handle_signal)
         return self
 
     def __exit__(self, *args):
         """Restore terminal settings"""
-        if sys.platform != "win32" and self.old_settings:
-            termios.tcsetattr(self.stdin_fd, termios.TCSADRAIN, self.old_settings)
+        try:
+            if sys.platform != "win32" and self.old_settings:
+                termios.tcsetattr(self.stdin_fd, termios.TCSADRAIN, self.old_settings)
+        except termios.error:
+            # After SIGHUP the tty is usually gone, so there is nothing to restore
+            pass
+        finally:
+            # signal.signal() returns None for handlers not installed from Python
+            for sig, handler in self.old_handlers.items():
+                signal.signal(sig, signal.SIG_DFL if handler is None else handler)
+            self.old_handlers = {}
+
+    def _handle_signal(self, signum, frame):
+        """Exit via SystemExit so cleanup and terminal restoration run"""
+        raise SystemExit(128 + signum)
 
     def get_key(self, timeout: float = 0.1) -> Optional[str]:
         """Get a single keypress with timeout - FIXED version"""
@@ -173,7 +201,7 @@ class TerminalDisplay:
     def draw_header(self):
         """Draw the search interface header"""
         self.move_cursor(1, 1)
-        print("🔍 REAL-TIME SEARCH")
+        print(f"{icon('search')} REAL-TIME SEARCH")
         print("=" * 60)
         print("Type to search • ↑↓ to select • Enter to open • ESC to exit")
         print("─" * 60)
@@ -204,7 +232,7 @@ class TerminalDisplay:
 
                 # Show result info
                 date_str = result.timestamp.strftime("%Y-%m-%d")
-                project = Path(result.file_path).parent.name[:20]
+                project = truncate_left(decode_project_name(Path(result.file_path).parent.name), 20)
 
                 # Highlight matching text
                 preview = result.context[:60].replace("\n", " ")
@@ -213,11 +241,11 @@ class TerminalDisplay:
                     idx = preview.lower().find(query.lower())
                     preview = (
                         preview[:idx]
-                        + f"\033[93m{preview[idx:idx + len(query)]}\033[0m"
+                        + highlight(preview[idx:idx + len(query)])
                         + preview[idx + len(query) :]
                     )
 
-                print(f"📄 {date_str} | {project} | {preview}...")
+                print(f"{icon('file')} {date_str} | {project} | {preview}...")
 
         self.last_result_count = len(results[:10])
 
@@ -533,10 +561,10 @@ def main():
     selected_file = rts.run()
     
     if selected_file:
-        print(f"\n✅ Selected: {selected_file}")
+        print(f"\n{icon('success')} Selected: {selected_file}")
         # Could optionally extract here
     else:
-        print("\n👋 Search cancelled")
+        print(f"\n{icon('bye')} Search cancelled")
 
 
 if __name__ == "__main__":
@@ -11,11 +11,13 @@ try:
     from .search_conversations import ConversationSearcher
     from .realtime_search import create_smart_searcher
     from .extract_claude_logs import ClaudeConversationExtractor
+    from .terminal_style import icon
 except ImportError:
     # Fallback for direct execution or when not installed as package
     from search_conversations import ConversationSearcher
     from realtime_search import create_smart_searcher
     from extract_claude_logs import ClaudeConversationExtractor
+    from terminal_style import icon
 
 
 def main():
@@ -27,16 +29,16 @@ def main():
     else:
         # Prompt for search term
         try:
-            search_term = input("🔍 Enter search term: ").strip()
+            search_term = input(f"{icon('search')} Enter search term: ").strip()
         except (EOFError, KeyboardInterrupt):
-            print("\n👋 Search cancelled")
+            print(f"\n{icon('bye')} Search cancelled")
             return
     
     if not search_term:
-        print("❌ No search term provided")
+        print(f"{icon('error')} No search term provided")
         return
     
-    print(f"\n🔍 Searching for: '{search_term}'")
+    print(f"\n{icon('search')} Searching for: '{search_term}'")
     print("=" * 60)
     
     # Initialize searcher
@@ -47,7 +49,7 @@ def main():
     results = smart_searcher.search(search_term, max_results=20)
     
     if results:
-        print(f"\n✅ Found {len(results)} results across conversations:\n")
+        print(f"\n{icon('success')} Found {len(results)} results across conversations:\n")
         
         # Group by file
         by_file = {}
@@ -96,15 +98,17 @@ def main():
                     # View conversation
                     if len(session_paths) == 1:
                         # Only one result, view it directly
-                        extractor.display_conversation(session_paths[0])
+                        extractor.display_conversation(session_paths[0], query=search_term)
                         
                         # After viewing, offer to extract
-                        extract_choice = input("\n📤 Extract this conversation? (y/N): ").strip().lower()
+                        extract_choice = input(
+                            f"\n{icon('export')} Extract this conversation? (y/N): "
+                        ).strip().lower()
                         if extract_choice == 'y':
                             conversation = extractor.extract_conversation(session_paths[0])
                             if conversation:
                                 output = extractor.save_as_markdown(conversation, sessions[0][1])
-                                print(f"✅ Saved: {output.name}")
+                                print(f"{icon('success')} Saved: {output.name}")
                     else:
                         # Multiple results, let user choose
                         print("\nSelect conversation to view:")
@@ -114,35 +118,39 @@ def main():
                         try:
                             view_num = int(input("\nEnter number (1-{}): ".format(len(sessions))))
                             if 1 <= view_num <= len(session_paths):
-                                extractor.display_conversation(session_paths[view_num - 1])
+                                extractor.display_conversation(
+                                    session_paths[view_num - 1], query=search_term
+                                )
                                 
                                 # After viewing, offer to extract
-                                extract_choice = input("\n📤 Extract this conversation? (y/N): ").strip().lower()
+                                extract_choice = input(
+                                    f"\n{icon('export')} Extract this conversation? (y/N): "
+                                ).strip().lower()
                                 if extract_choice == 'y':
                                     conversation = extractor.extract_conversation(session_paths[view_num - 1])
                                     if conversation:
                                         output = extractor.save_as_markdown(conversation, sessions[view_num - 1][1])
-                                        print(f"✅ Saved: {output.name}")
+                                        print(f"{icon('success')} Saved: {output.name}")
                         except (ValueError, IndexError):
-                            print("❌ Invalid selection")
+                            print(f"{icon('error')} Invalid selection")
                 
                 elif choice == 'E':
                     # Extract all found conversations
                     for i, (session_path, (fname, sid)) in enumerate(zip(session_paths, sessions), 1):
-                        print(f"\n📤 Extracting session {i}...")
+                        print(f"\n{icon('export')} Extracting session {i}...")
                         conversation = extractor.extract_conversation(session_path)
                         if conversation:
                             output = extractor.save_as_markdown(conversation, sid)
-                            print(f"✅ Saved: {output.name}")
+                            print(f"{icon('success')} Saved: {output.name}")
                 
                 elif choice == 'Q':
-                    print("\n👋 Goodbye!")
+                    print(f"\n{icon('bye')} Goodbye!")
                     
             except (EOFError, KeyboardInterrupt):
-                print("\n👋 Search cancelled")
+                print(f"\n{icon('bye')} Search cancelled")
     else:
-        print(f"\n❌ No matches found for '{search_term}'")
-        print("\n💡 Tips:")
+        print(f"\n{icon('error')} No matches found for '{search_term}'")
+        print(f"\n{icon('tip')} Tips:")
         print("   - Try a more general search term")
         print("   - Search is case-insensitive by default")
         print("   - Partial matches are included")
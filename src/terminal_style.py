@@ -0,0 +1,72 @@
+#!/usr/bin/env python3
+"""
+Terminal styling helpers shared by the command-line interfaces.
+
+Icons are printed as emoji by default. Some terminals render emoji badly, so
+setting CLAUDE_EXTRACT_ASCII=1 (or passing --ascii to claude-extract) switches
+every icon to a plain ASCII fallback.
+
+Colors follow the NO_COLOR convention (https://no-color.org): when NO_COLOR is
+set to a non-empty value (or --no-color is passed), no color escapes are
+printed and search matches are marked with brackets instead.
+"""
+
+import os
+
+ASCII_ENV_VAR = "CLAUDE_EXTRACT_ASCII"
+NO_COLOR_ENV_VAR = "NO_COLOR"
+
+# name -> (emoji, ASCII fallback)
+ICONS = {
+    "assistant": ("🤖", "[C]"),
+    "bye": ("👋", "[BYE]"),
+    "calendar": ("📅", "[DATE]"),
+    "details": ("📋", "[INFO]"),
+    "disk": ("💾", "[SIZE]"),
+    "error": ("❌", "[ERROR]"),
+    "export": ("📤", "[>>]"),
+    "file": ("📄", "[FILE]"),
+    "folder": ("📁", "[DIR]"),
+    "info": ("ℹ️", "[i]"),
+    "library": ("📚", "[LIST]"),
+    "messages": ("💬", "[MSGS]"),
+    "note": ("📝", "[TEXT]"),
+    "open_folder": ("🗂️", "[DIR]"),
+    "search": ("🔍", "[SEARCH]"),
+    "skip": ("⏭️", "[SKIP]"),
+    "sparkle": ("✨", "*"),
+    "stats": ("📊", "[SUMMARY]"),
+    "success": ("✅", "[OK]"),
+    "tip": ("💡", "[TIP]"),
+    "tool": ("🔧", "[T]"),
+    "user": ("👤", "[H]"),
+    "warning": ("⚠️", "[WARN]"),
+}
+
+
+def use_ascii() -> bool:
+    """Return True when icons should be printed as ASCII fallbacks."""
+    return os.environ.get(ASCII_ENV_VAR, "").strip().lower() not in ("", "0", "false", "no")
+
+
+def icon(name: str) -> str:
+    """Return the icon for name, honoring the ASCII mode setting."""
+    emoji, fallback = ICONS[name]
+    return fallback if use_ascii() else emoji
+
+
+def use_color() -> bool:
+    """Return True unless NO_COLOR is set to a non-empty value."""
+    return not os.environ.get(NO_COLOR_ENV_VAR)
+
+
+def ansi(code: str) -> str:
+    """Return the ANSI SGR escape for code, or an empty string when color is off."""
+    return f"\033[{code}m" if use_color() else ""
+
+
+def highlight(text: str) -> str:
+    """Mark a search match in bright yellow, or with brackets when color is off."""
+    if use_color():
+        return f"\033[93m{text}\033[0m"
+    return f"[{text}]"
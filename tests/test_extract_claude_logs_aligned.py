@@ -15,7 +15,12 @@ from unittest.mock import Mock, patch
 sys.path.append(str(Path(__file__).parent.parent))
 
 # Local imports after sys.path modification
-from extract_claude_logs import ClaudeConversationExtractor, main  # noqa: E402
+from extract_claude_logs import (  # noqa: E402
+    ClaudeConversationExtractor,
+    decode_project_name,
+    main,
+    truncate_left,
+)
 
 
 class TestClaudeConversationExtractor(unittest.TestCase):
@@ -327,6 +332,136 @@ class TestClaudeConversationExtractor(unittest.TestCase):
                 any("Invalid session number" in str(call) for call in print_calls)
             )
 
+    @patch.dict("os.environ", {"NO_COLOR": ""})
+    def test_display_conversation_jumps_to_first_match(self):
+        """Test viewing with a query starts at and highlights the first match"""
+        messages = [
+            {"role": "user", "content": "Hello there", "timestamp": ""},
+            {"role": "assistant", "content": "Nothing here", "timestamp": ""},
+            {"role": "user", "content": "Fix the Python error", "timestamp": ""},
+        ]
+        with patch.object(self.extractor, "extract_conversation", return_value=messages):
+            with patch("builtins.input", return_value=""):
+                with patch("builtins.print") as mock_print:
+                    self.extractor.display_conversation(
+                        Path("chat.jsonl"), query="python error"
+                    )
+
+        output = "\n".join(str(call) for call in mock_print.call_args_list)
+        self.assertIn("message 3 of 3", output)
+        self.assertNotIn("Hello there", output)
+        self.assertIn("\\x1b[93mPython\\x1b[0m", output)
+        self.assertIn("\\x1b[93merror\\x1b[0m", output)
+
+    def test_display_conversation_invalid_regex_shows_all(self):
+        """Test an invalid regex query falls back to viewing from the start"""
+        messages = [{"role": "user", "content": "Hello there", "timestamp": ""}]
+        with patch.object(self.extractor, "extract_conversation", return_value=messages):
+            with patch("builtins.input", return_value=""):
+                with patch("builtins.print") as mock_print:
+                    self.extractor.display_conversation(
+                        Path("chat.jsonl"), query="(", regex=True
+                    )
+
+        output = "\n".join(str(call) for call in mock_print.call_args_list)
+        self.assertIn("Hello there", output)
+        self.assertNotIn("Jumped", output)
+
+    @patch.dict("os.environ", {"NO_COLOR": "1"})
+    def test_display_conversation_ignores_zero_width_matches(self):
+        """Test a regex that only matches empty strings neither jumps nor highlights"""
+        messages = [
+            {"role": "user", "content": "hello", "timestamp": ""},
+            {"role": "assistant", "content": "world", "timestamp": ""},
+        ]
+        with patch.object(self.extractor, "extract_conversation", return_value=messages):
+            with patch("builtins.input", return_value=""):
+                with patch("builtins.print") as mock_print:
+                    self.extractor.display_conversation(
+                        Path("chat.jsonl"), query="x*", regex=True
+                    )
+
+        printed = [call.args[0] for call in mock_print.call_args_list if call.args]
+        self.assertIn("hello", printed)
+        self.assertIn("world", printed)
+        self.assertFalse(any("[]" in str(line) for line in printed))
+        self.assertFalse(any("Jumped" in str(line) for line in printed))
+
+    @patch.dict("os.environ", {"NO_COLOR": "1"})
+    def test_display_conversation_cycles_matches(self):
+        """Test N and P at a page break jump between matching messages, wrapping at the end"""
+        filler = "\n".join(f"line {n}" for n in range(30))
+        messages = [
+            {"role": "user", "content": "first target", "timestamp": ""},
+            {"role": "assistant", "content": filler, "timestamp": ""},
+            {"role": "user", "content": "second target", "timestamp": ""},
+            {"role": "assistant", "content": filler, "timestamp": ""},
+        ]
+        responses = iter(["N", "N", "P", "Q"])
+        with patch.object(self.extractor, "extract_conversation", return_value=messages):
+            with patch("builtins.input", side_effect=responses) as mock_input:
+                with patch("builtins.print") as mock_print:
+                    self.extractor.display_conversation(Path("chat.jsonl"), query="target")
+
+        self.assertIn("Next/Previous match", mock_input.call_args_list[0].args[0])
+        printed = [str(call.args[0]) for call in mock_print.call_args_list if call.args]
+        jumps = [line for line in printed if line.startswith("Match ")]
+        self.assertEqual(
+            jumps,
+            [
+                "Match 2 of 2 (message 3 of 4)",
+                "Match 1 of 2 (message 1 of 4)",
+                "Match 1 of 2 (message 1 of 4)",
+            ],
+        )
+
+    @patch.dict("os.environ", {"CLAUDE_EXTRACT_ASCII": "1"})
+    def test_display_conversation_ascii_detailed_content(self):
+        """Test ASCII mode replaces emoji in detailed message content when viewing"""
+        messages = [
+            {"role": "tool_use", "content": "🔧 Tool: Bash\nInput: {}", "timestamp": ""},
+            {"role": "tool_result", "content": "📤 Result:\nok", "timestamp": ""},
+            {"role": "system", "content": "ℹ️ System: note", "timestamp": ""},
+            {"role": "assistant", "content": "Done\n🔧 Using tool: Read", "timestamp": ""},
+        ]
+        with patch.object(self.extractor, "extract_conversation", return_value=messages):
+            with patch("builtins.input", return_value=""):
+                with patch("builtins.print") as mock_print:
+                    self.extractor.display_conversation(Path("chat.jsonl"), detailed=True)
+
+        printed = [str(call.args[0]) for call in mock_print.call_args_list if call.args]
+        self.assertIn("[T] Tool: Bash", printed)
+        self.assertIn("[>>] Result:", printed)
+        self.assertIn("[i] System: note", printed)
+        self.assertIn("[T] Using tool: Read", printed)
+        for emoji in ("🔧", "📤", "ℹ️"):
+            self.assertFalse(any(emoji in line for line in printed), emoji)
+
+    def test_write_run_summary(self):
+        """Test run summary records skipped/failed sessions and writes a log"""
+        sessions = [Path("session1.jsonl"), Path("session2.jsonl")]
+
+        with patch.object(self.extractor, "extract_conversation", return_value=[]):
+            with patch("builtins.print"):
+                self.extractor.extract_multiple(sessions, [0, 5])
+                log_path = self.extractor.write_run_summary()
+
+        self.assertEqual(self.extractor.last_run["processed"], 2)
+        self.assertEqual(
+            self.extractor.last_run["skipped"], [("session1.jsonl", "no conversation")]
+        )
+        self.assertEqual(len(self.extractor.last_run["failed"]), 1)
+        self.assertEqual(log_path, Path(self.temp_dir) / "export-summary.log")
+        content = log_path.read_text(encoding="utf-8")
+        self.assertIn("Processed:     2", content)
+        self.assertIn("Skipped:       1", content)
+        self.assertIn("session1.jsonl: no conversation", content)
+        self.assertIn("#6: invalid session number", content)
+
+    def test_write_run_summary_without_run(self):
+        """Test run summary is a no-op before any extraction"""
+        self.assertIsNone(self.extractor.write_run_summary())
+
 
 class TestMainFunction(unittest.TestCase):
     """Test the main() function and command-line interface"""
@@ -469,5 +604,96 @@ class TestMainFunction(unittest.TestCase):
                     self.assertTrue(any("Invalid" in str(call) for call in print_calls))
 
 
+class TestDecodeProjectName(unittest.TestCase):
+    """Test reconstruction of Claude's encoded project directory names"""
+
+    def setUp(self):
+        self.temp_dir = Path(tempfile.mkdtemp()).resolve()
+        decode_project_name.cache_clear()
+
+    def tearDown(self):
+        import shutil
+
+        shutil.rmtree(self.temp_dir, ignore_errors=True)
+
+    def _encode(self, path):
+        import re
+
+        return re.sub(r"[^A-Za-z0-9]", "-", path.as_posix())
+
+    def test_decode_existing_path_with_hyphens_and_dots(self):
+        """Test hyphens, dots and underscores in real names are recovered"""
+        project = self.temp_dir / "my_proj.v2" / "sub-dir"
+        project.mkdir(parents=True)
+        with patch("pathlib.Path.home", return_value=Path("/nonexistent-home")):
+            self.assertEqual(decode_project_name(self._encode(project)), project.as_posix())
+
+    def test_decode_under_home_uses_tilde(self):
+        """Test paths under the home directory are shown relative to ~"""
+        project = self.temp_dir / "code" / "app"
+        project.mkdir(parents=True)
+        with patch("pathlib.Path.home", return_value=self.temp_dir):
+            self.assertEqual(decode_project_name(self._encode(project)), "~/code/app")
+            self.assertEqual(decode_project_name(self._encode(self.temp_dir)), "~")
+
+    def test_decode_backtracks_on_ambiguous_names(self):
+        """Test a longer directory name that leads to a dead end is abandoned"""
+        (self.temp_dir / "a" / "my-proj").mkdir(parents=True)
+        project = self.temp_dir / "a" / "my" / "proj" / "sub"
+        project.mkdir(parents=True)
+        with patch("pathlib.Path.home", return_value=Path("/nonexistent-home")):
+            self.assertEqual(decode_project_name(self._encode(project)), project.as_posix())
+            self.assertEqual(
+                decode_project_name(self._encode(self.temp_dir / "a" / "my-proj")),
+                (self.temp_dir / "a" / "my-proj").as_posix(),
+            )
+
+    def test_decode_only_stats_matching_entries(self):
+        """Test is_dir is checked only for entries whose name matches"""
+        project = self.temp_dir / "app"
+        project.mkdir()
+        for i in range(5):
+            (self.temp_dir / f"other{i}").mkdir()
+        checked = []
+        original = Path.is_dir
+
+        def tracking_is_dir(path):
+            checked.append(path)
+            return original(path)
+
+        with patch("pathlib.Path.home", return_value=Path("/nonexistent-home")):
+            with patch("pathlib.Path.is_dir", tracking_is_dir):
+                decode_project_name(self._encode(project))
+        self.assertFalse(any(path.parent == self.temp_dir and path != project for path in checked))
+
+    def test_decode_windows_drive_letter(self):
+        """Test C--Users-... names are decoded under the drive root"""
+        with patch("pathlib.Path.home", return_value=Path("/nonexistent-home")):
+            self.assertEqual(decode_project_name("C--Users-me-proj"), "C:/Users/me/proj")
+
+    def test_decode_missing_path_falls_back_to_separators(self):
+        """Test unknown paths treat every hyphen as a separator"""
+        encoded = self._encode(self.temp_dir) + "-gone-away"
+        with patch("pathlib.Path.home", return_value=Path("/nonexistent-home")):
+            self.assertEqual(
+                decode_project_name(encoded), (self.temp_dir / "gone" / "away").as_posix()
+            )
+
+    def test_decode_unencoded_name_unchanged(self):
+        """Test names without the encoded prefix are returned as-is"""
+        self.assertEqual(decode_project_name("project1"), "project1")
+
+    def test_truncate_left_keeps_project_end(self):
+        """Test long paths keep their last characters behind an ellipsis"""
+        self.assertEqual(truncate_left("~/Documents/GitHub/my-proj", 12), "…Hub/my-proj")
+        self.assertEqual(truncate_left("~/code", 12), "~/code")
+
+    def test_truncate_left_tiny_widths(self):
+        """Test widths of one or less never return more than width characters"""
+        self.assertEqual(truncate_left("abcdef", 2), "…f")
+        self.assertEqual(truncate_left("abcdef", 1), "…")
+        self.assertEqual(truncate_left("abcdef", 0), "")
+
+
 if __name__ == "__main__":
     unittest.main()
@@ -141,6 +141,69 @@ class TestKeyboardHandlerCoverage(unittest.TestCase):
                 0, mock_termios.TCSADRAIN, old_settings
             )
 
+    @patch("sys.platform", "linux")
+    def test_unix_keyboard_restores_on_termination_signal(self):
+        """Test SIGTERM/SIGHUP exit cleanly and handlers are restored"""
+        import signal
+
+        with patch("sys.stdin.fileno", return_value=0), patch(
+            "realtime_search.termios"
+        ) as mock_termios, patch("realtime_search.tty"):
+            mock_termios.tcgetattr.return_value = "old_settings"
+            previous = signal.getsignal(signal.SIGTERM)
+
+            handler = KeyboardHandler()
+            with self.assertRaises(SystemExit) as ctx:
+                with handler:
+                    self.assertEqual(
+                        signal.getsignal(signal.SIGTERM), handler._handle_signal
+                    )
+                    handler._handle_signal(signal.SIGTERM, None)
+
+            self.assertEqual(ctx.exception.code, 128 + signal.SIGTERM)
+            mock_termios.tcsetattr.assert_called_once_with(
+                0, mock_termios.TCSADRAIN, "old_settings"
+            )
+            self.assertEqual(signal.getsignal(signal.SIGTERM), previous)
+
+    @patch("sys.platform", "linux")
+    def test_unix_keyboard_hangup_without_tty(self):
+        """Test SIGHUP exits with 129 and restores handlers when the tty is gone"""
+        import signal
+        import termios
+
+        with patch("sys.stdin.fileno", return_value=0), patch(
+            "realtime_search.termios"
+        ) as mock_termios, patch("realtime_search.tty"):
+            mock_termios.error = termios.error
+            mock_termios.tcgetattr.return_value = "old_settings"
+            mock_termios.tcsetattr.side_effect = termios.error("no tty")
+            previous = signal.getsignal(signal.SIGHUP)
+
+            handler = KeyboardHandler()
+            with self.assertRaises(SystemExit) as ctx:
+                with handler:
+                    handler._handle_signal(signal.SIGHUP, None)
+
+            self.assertEqual(ctx.exception.code, 129)
+            self.assertEqual(signal.getsignal(signal.SIGHUP), previous)
+
+    @patch("sys.platform", "linux")
+    def test_unix_keyboard_restores_default_for_foreign_handler(self):
+        """Test a handler not installed from Python is restored as SIG_DFL"""
+        import signal
+
+        with patch("sys.stdin.fileno", return_value=0), patch(
+            "realtime_search.termios"
+        ), patch("realtime_search.tty"), patch(
+            "realtime_search.signal.signal", return_value=None
+        ) as mock_signal:
+            with KeyboardHandler():
+                pass
+
+            mock_signal.assert_any_call(signal.SIGTERM, signal.SIG_DFL)
+            mock_signal.assert_any_call(signal.SIGHUP, signal.SIG_DFL)
+
     @patch("sys.platform", "linux")
     def test_unix_keyboard_special_sequences(self):
         """Test Unix escape sequences"""
@@ -0,0 +1,102 @@
+#!/usr/bin/env python3
+"""
+Tests for terminal_style.py icon and color handling
+"""
+
+import os
+import sys
+import unittest
+from pathlib import Path
+from unittest.mock import patch
+
+# Add parent directory to path before local imports
+sys.path.append(str(Path(__file__).parent.parent))
+
+# Local imports after sys.path modification
+from terminal_style import (  # noqa: E402
+    ASCII_ENV_VAR,
+    ICONS,
+    NO_COLOR_ENV_VAR,
+    ansi,
+    highlight,
+    icon,
+    use_ascii,
+    use_color,
+)
+
+
+class TestIcons(unittest.TestCase):
+    """Test emoji icons and their ASCII fallbacks"""
+
+    def test_emoji_by_default(self):
+        """Test icons are emoji when ASCII mode is not set"""
+        with patch.dict(os.environ, {}, clear=True):
+            self.assertFalse(use_ascii())
+            self.assertEqual(icon("success"), "✅")
+
+    def test_ascii_mode_from_environment(self):
+        """Test the environment variable switches every icon to ASCII"""
+        with patch.dict(os.environ, {ASCII_ENV_VAR: "1"}):
+            self.assertTrue(use_ascii())
+            for name in ICONS:
+                self.assertTrue(icon(name).isascii(), name)
+            self.assertEqual(icon("error"), "[ERROR]")
+
+    def test_ascii_mode_disabled_values(self):
+        """Test falsy environment values keep emoji"""
+        for value in ("", "0", "false", "no"):
+            with patch.dict(os.environ, {ASCII_ENV_VAR: value}):
+                self.assertFalse(use_ascii(), value)
+
+    def test_main_ascii_flag_sets_environment(self):
+        """Test --ascii enables ASCII icons for the rest of the run"""
+        from extract_claude_logs import ClaudeConversationExtractor, main
+
+        with patch.dict(os.environ, {}, clear=True):
+            with patch("sys.argv", ["claude-extract", "--ascii", "--list"]):
+                with patch.object(ClaudeConversationExtractor, "__init__", return_value=None):
+                    with patch.object(
+                        ClaudeConversationExtractor, "list_recent_sessions", return_value=[]
+                    ):
+                        main()
+            self.assertEqual(os.environ.get(ASCII_ENV_VAR), "1")
+
+
+class TestColors(unittest.TestCase):
+    """Test NO_COLOR handling"""
+
+    def test_color_by_default(self):
+        """Test color escapes are used when NO_COLOR is unset"""
+        with patch.dict(os.environ, {}, clear=True):
+            self.assertTrue(use_color())
+            self.assertEqual(ansi("95"), "\033[95m")
+            self.assertEqual(highlight("match"), "\033[93mmatch\033[0m")
+
+    def test_no_color_disables_escapes(self):
+        """Test NO_COLOR removes escapes and marks matches with brackets"""
+        with patch.dict(os.environ, {NO_COLOR_ENV_VAR: "1"}):
+            self.assertFalse(use_color())
+            self.assertEqual(ansi("95"), "")
+            self.assertEqual(highlight("match"), "[match]")
+
+    def test_empty_no_color_keeps_color(self):
+        """Test an empty NO_COLOR value is ignored, as the convention specifies"""
+        with patch.dict(os.environ, {NO_COLOR_ENV_VAR: ""}):
+            self.assertTrue(use_color())
+
+    def test_main_no_color_flag_sets_environment(self):
+        """Test --no-color disables color for the rest of the run"""
+        from extract_claude_logs import ClaudeConversationExtractor, main
+
+        with patch.dict(os.environ, {}, clear=True):
+            with patch("sys.argv", ["claude-extract", "--no-color", "--list"]):
+                with patch.object(ClaudeConversationExtractor, "__init__", return_value=None):
+                    with patch.object(
+                        ClaudeConversationExtractor, "list_recent_sessions", return_value=[]
+                    ):
+                        main()
+            self.assertEqual(os.environ.get(NO_COLOR_ENV_VAR), "1")
+
+
+if __name__ == "__main__":
+    unittest.main()